@@ -0,0 +1,103 @@
+use std::collections::VecDeque;
+
+const WINDOW_SIZE: usize = 10;
+
+/// A windowed bottleneck-bandwidth estimator, based on the delivery-rate estimation idea
+/// from BBR.
+///
+/// Callers feed in delivered bytes and a feedback interval per received packet (or batch of
+/// packets). The instantaneous delivery rate `delivered / interval` is pushed through a
+/// windowed-max filter (over the last ~10 RTTs) to estimate the bottleneck bandwidth, while
+/// the ROTT of the same feedback is pushed through a windowed-min filter to estimate the
+/// propagation delay.
+///
+/// ```rust
+/// use packet_loss_classification::BandwidthEstimator;
+///
+/// let mut bandwidth = BandwidthEstimator::default();
+/// bandwidth.update(1_000.0, 1.0, 10.0);
+/// bandwidth.update(500.0, 1.0, 12.0);
+/// assert_eq!(bandwidth.bottleneck_bandwidth(), 1_000.0);
+/// assert_eq!(bandwidth.min_rott(), 10.0);
+/// ````
+#[derive(Debug)]
+pub struct BandwidthEstimator {
+    window_size: usize,
+    delivery_rates: VecDeque<f64>,
+    rotts: VecDeque<f64>,
+    last_delivery_rate: f64,
+}
+
+impl Default for BandwidthEstimator {
+    fn default() -> Self {
+        Self {
+            window_size: WINDOW_SIZE,
+            delivery_rates: VecDeque::with_capacity(WINDOW_SIZE),
+            rotts: VecDeque::with_capacity(WINDOW_SIZE),
+            last_delivery_rate: 0.0,
+        }
+    }
+}
+
+impl BandwidthEstimator {
+    /// Creates a new bandwidth estimator with the given windowed-max/min filter size.
+    ///
+    /// # Arguments
+    ///
+    /// - `window_size`: number of feedback samples (roughly RTTs) the filters are taken over.
+    pub fn new(window_size: usize) -> Self {
+        Self {
+            window_size,
+            delivery_rates: VecDeque::with_capacity(window_size),
+            rotts: VecDeque::with_capacity(window_size),
+            last_delivery_rate: 0.0,
+        }
+    }
+
+    /// Records a delivery-rate feedback sample.
+    ///
+    /// # Arguments
+    ///
+    /// - `delivered_bytes`: number of bytes delivered since the last feedback.
+    /// - `interval`: time elapsed since the last feedback.
+    /// - `rott`: relative one-way trip time observed for this feedback.
+    pub fn update(&mut self, delivered_bytes: f64, interval: f64, rott: f64) {
+        assert!(delivered_bytes >= 0.0);
+        assert!(interval > 0.0);
+        assert!(rott >= 0.0);
+
+        self.last_delivery_rate = delivered_bytes / interval;
+
+        self.delivery_rates.push_back(self.last_delivery_rate);
+        if self.delivery_rates.len() > self.window_size {
+            self.delivery_rates.pop_front();
+        }
+
+        self.rotts.push_back(rott);
+        if self.rotts.len() > self.window_size {
+            self.rotts.pop_front();
+        }
+    }
+
+    /// The windowed-max estimate of the bottleneck bandwidth.
+    pub fn bottleneck_bandwidth(&self) -> f64 {
+        self.delivery_rates.iter().cloned().fold(0.0, f64::max)
+    }
+
+    /// The windowed-min estimate of the propagation delay (minimum ROTT).
+    pub fn min_rott(&self) -> f64 {
+        self.rotts.iter().cloned().fold(std::f64::MAX, f64::min)
+    }
+
+    /// Whether the most recent delivery rate is pinned at the windowed-max bandwidth
+    /// estimate, indicating the sender is not being held back by a saturated bottleneck.
+    pub fn is_delivery_rate_pinned_at_max(&self) -> bool {
+        !self.delivery_rates.is_empty()
+            && self.last_delivery_rate >= 0.95 * self.bottleneck_bandwidth()
+    }
+
+    /// Whether any feedback has been recorded yet.
+    pub fn has_samples(&self) -> bool {
+        !self.delivery_rates.is_empty()
+    }
+}
@@ -0,0 +1,141 @@
+use time::OffsetDateTime;
+
+use crate::{PacketLoss, ZBS};
+
+/// A single timestamped packet observation fed into [`Classifier::observe`].
+#[derive(Debug, Clone, Copy)]
+pub struct Sample {
+    /// Timestamp at which the packet was sent.
+    pub send_time: OffsetDateTime,
+    /// Timestamp at which the packet arrived.
+    pub arrival_time: OffsetDateTime,
+    /// Sequence number of the packet, used to detect loss gaps and reordering.
+    pub sequence_number: u32,
+}
+
+/// A stateful timestamp-based front-end that derives ROTT, interarrival time and
+/// `num_lost_packets` from raw `(send_time, arrival_time, sequence_number)` samples, feeding
+/// them into an inner [`ZBS`] classifier.
+///
+/// This removes the burden of timing bookkeeping from every consumer, who would otherwise
+/// have to compute ROTT and interarrival time themselves before calling [`ZBS::classify`].
+/// [`observe`] only returns a classification once a loss gap is detected; reordered,
+/// duplicate or malformed samples are ignored rather than advancing state (be it a stale
+/// sequence number, one that arrives out of timestamp order despite a forward sequence
+/// number since packets can take different paths, or an `arrival_time` before `send_time`
+/// due to clock skew), and sequence numbers are allowed to wrap around `u32::MAX`.
+///
+/// ```rust
+/// use packet_loss_classification::{Classifier, Sample, ZBS};
+/// use time::{Duration, OffsetDateTime};
+///
+/// let mut classifier = Classifier::new(ZBS::default());
+/// let base = OffsetDateTime::UNIX_EPOCH;
+///
+/// for sequence_number in 0..3u32 {
+///     let time = base + Duration::seconds(sequence_number as i64);
+///     assert!(classifier
+///         .observe(Sample {
+///             send_time: time,
+///             arrival_time: time,
+///             sequence_number,
+///         })
+///         .is_none());
+/// }
+///
+/// // Packet 3 was lost, packet 4 arrives with extra delay.
+/// let verdict = classifier.observe(Sample {
+///     send_time: base + Duration::seconds(4),
+///     arrival_time: base + Duration::seconds(8),
+///     sequence_number: 4,
+/// });
+/// assert!(verdict.is_some());
+///
+/// // Packet 10 has a forward sequence number but took a faster path and arrives before the
+/// // timestamp baseline packet 4 already established: it is ignored rather than panicking.
+/// let verdict = classifier.observe(Sample {
+///     send_time: base + Duration::seconds(10),
+///     arrival_time: base + Duration::seconds(6),
+///     sequence_number: 10,
+/// });
+/// assert!(verdict.is_none());
+/// ````
+///
+/// [`ZBS`]: struct.ZBS.html
+/// [`ZBS::classify`]: struct.ZBS.html#method.classify
+/// [`observe`]: Classifier::observe
+#[derive(Debug)]
+pub struct Classifier {
+    zbs: ZBS,
+    arrival_min: Option<f64>,
+    last_arrival: Option<OffsetDateTime>,
+    last_sequence_number: Option<u32>,
+}
+
+impl Classifier {
+    /// Creates a new timestamp-based classifier front-end wrapping the given [`ZBS`] instance.
+    ///
+    /// [`ZBS`]: struct.ZBS.html
+    pub fn new(zbs: ZBS) -> Self {
+        Self {
+            zbs,
+            arrival_min: None,
+            last_arrival: None,
+            last_sequence_number: None,
+        }
+    }
+
+    /// Observes a single timestamped packet sample, returning a classification once a loss
+    /// gap is detected between it and the previously observed packet.
+    ///
+    /// # Arguments
+    ///
+    /// - `sample`: the send timestamp, arrival timestamp and sequence number of the packet.
+    pub fn observe(&mut self, sample: Sample) -> Option<PacketLoss> {
+        if sample.arrival_time < sample.send_time {
+            // A packet cannot arrive before it was sent; this points at clock skew between
+            // the timestamps rather than a trustworthy sample. Ignore it without advancing
+            // state, the same way a reordered sample is ignored below.
+            return None;
+        }
+
+        if let Some(last_arrival) = self.last_arrival {
+            if sample.arrival_time < last_arrival {
+                // Reordered sample: it carries a forward sequence number but, e.g. having
+                // taken a different path or queue, arrived before a packet we already used
+                // as the interarrival-time baseline. Ignore it without advancing state.
+                return None;
+            }
+        }
+
+        let one_way = (sample.arrival_time - sample.send_time).as_seconds_f64();
+        let arrival_min = self.arrival_min.map_or(one_way, |min| min.min(one_way));
+        let rott = one_way - arrival_min;
+
+        let verdict = match self.last_sequence_number {
+            Some(last_sequence_number) => {
+                let gap = sample.sequence_number.wrapping_sub(last_sequence_number);
+                if gap == 0 || gap > u32::MAX / 2 {
+                    // Reordered or duplicate sequence number: ignore without advancing state.
+                    return None;
+                }
+
+                let num_lost_packets = gap - 1;
+                if num_lost_packets == 0 {
+                    None
+                } else {
+                    let last_arrival = self.last_arrival.expect("set alongside last_sequence_number");
+                    let interarrival_time = (sample.arrival_time - last_arrival).as_seconds_f64();
+                    Some(self.zbs.classify(rott, interarrival_time, num_lost_packets))
+                }
+            }
+            None => None,
+        };
+
+        self.arrival_min = Some(arrival_min);
+        self.last_sequence_number = Some(sample.sequence_number);
+        self.last_arrival = Some(sample.arrival_time);
+
+        verdict
+    }
+}
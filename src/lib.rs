@@ -4,10 +4,11 @@
 //! Depending on the reason behind such errors an application might have to
 //! take different measures to enhance performance.
 //!
-//! This crate provides five classifiers ([`MBiaz`], [`Spike`], [`ZigZag`], [`ZBS`] and [`Trend`])
-//! for packet loss classification. Each performs well under certain circumstances and it is
-//! up to the user to decide on the best fit. [`ZBS`] being a hybrid version of the first four
-//! can lead to good results across a number of network topologies based on topology estimation.
+//! This crate provides six classifiers ([`MBiaz`], [`Spike`], [`ZigZag`], [`ZBS`], [`Trend`]
+//! and [`Trendline`]) for packet loss classification. Each performs well under certain
+//! circumstances and it is up to the user to decide on the best fit. [`ZBS`] being a hybrid
+//! of [`MBiaz`], [`Spike`], [`ZigZag`] and [`Trendline`] can lead to good results across a
+//! number of network topologies based on topology estimation.
 //!
 //! For the theory behind all algorithms the following two papers (where theory and algorithms
 //! are taken from) are highly recommended:
@@ -15,9 +16,26 @@
 //! - Cen, Song, Pamela C. Cosman, and Geoffrey M. Voelker. "End-to-end differentiation of congestion and wireless losses." IEEE/ACM Transactions on Networking (TON) 11.5 (2003): 703-717
 //! - Hsiao, Hsu-Feng, et al. "A new multimedia packet loss classification algorithm for congestion control over wired/wireless channels." Proceedings.(ICASSP'05). IEEE International Conference on Acoustics, Speech, and Signal Processing, 2005.. Vol. 2. IEEE, 2005.
 //!
+//! [`Trendline`] additionally draws on the delay-gradient estimator from Google Congestion
+//! Control (GCC), used for real-time media congestion control in WebRTC. GCC's rate
+//! controller is also available as [`RateController`], which turns a stream of
+//! classifications into an `Increase`/`Hold`/`Decrease` [`Action`] recommendation.
+//!
+//! [`ZBS`] can additionally be fed delivery-rate feedback, which it runs through a
+//! [`BandwidthEstimator`] (inspired by BBR) to sharpen its topology estimation.
+//!
+//! Instead of computing ROTT and interarrival times by hand, callers may instead push raw
+//! `(send_time, arrival_time, sequence_number)` samples into [`Classifier`], which derives
+//! that timing bookkeeping itself and drives an inner [`ZBS`].
+//!
+//! [`Action`]: enum.Action.html
+//! [`BandwidthEstimator`]: struct.BandwidthEstimator.html
+//! [`Classifier`]: struct.Classifier.html
 //! [`MBiaz`]: struct.MBiaz.html
+//! [`RateController`]: struct.RateController.html
 //! [`Spike`]: struct.Spike.html
 //! [`Trend`]: struct.Trend.html
+//! [`Trendline`]: struct.Trendline.html
 //! [`ZBS`]: struct.ZBS.html
 //! [`ZigZag`]: struct.ZigZag.html
 
@@ -28,16 +46,30 @@ pub enum PacketLoss {
     Congestion,
     /// Packet loss due to wireless error.
     Wireless,
+    /// Packet loss due to a sustained congestion event rather than an isolated
+    /// congestion spike, borrowed from QUIC's persistent congestion detection.
+    ///
+    /// A sender seeing this should reset to its minimum window instead of merely
+    /// backing off, since the path is likely collapsed rather than briefly queued.
+    PersistentCongestion,
 }
 
+mod bandwidth;
+mod classifier;
 mod mbiaz;
+mod rate_control;
 mod spike;
 mod trend;
+mod trendline;
 mod zbs;
 mod zigzag;
 
+pub use bandwidth::BandwidthEstimator;
+pub use classifier::{Classifier, Sample};
 pub use mbiaz::MBiaz;
+pub use rate_control::{Action, RateController, UsageSignal};
 pub use spike::Spike;
 pub use trend::Trend;
+pub use trendline::Trendline;
 pub use zbs::ZBS;
 pub use zigzag::ZigZag;
@@ -0,0 +1,145 @@
+use crate::PacketLoss;
+
+/// A usage signal driving the [`RateController`] state machine.
+///
+/// This is a superset of [`PacketLoss`]: besides the congestion/wireless verdict
+/// every classifier already produces, [`Trendline`] can additionally observe the
+/// link being underused, which [`PacketLoss`] has no variant for.
+///
+/// [`PacketLoss`]: enum.PacketLoss.html
+/// [`RateController`]: struct.RateController.html
+/// [`Trendline`]: struct.Trendline.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UsageSignal {
+    /// The link is overused, e.g. a [`PacketLoss::Congestion`] verdict.
+    ///
+    /// [`PacketLoss::Congestion`]: enum.PacketLoss.html
+    Overuse,
+    /// The link behaves normally, e.g. a [`PacketLoss::Wireless`] verdict.
+    ///
+    /// [`PacketLoss::Wireless`]: enum.PacketLoss.html
+    Normal,
+    /// The link is underused.
+    Underuse,
+}
+
+impl From<PacketLoss> for UsageSignal {
+    fn from(loss: PacketLoss) -> Self {
+        match loss {
+            PacketLoss::Congestion | PacketLoss::PersistentCongestion => UsageSignal::Overuse,
+            PacketLoss::Wireless => UsageSignal::Normal,
+        }
+    }
+}
+
+/// A sending rate recommendation produced by [`RateController`].
+///
+/// [`RateController`]: struct.RateController.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Action {
+    /// The sending rate should be increased.
+    Increase,
+    /// The sending rate should be held steady.
+    Hold,
+    /// The sending rate should be decreased.
+    Decrease,
+}
+
+/// A rate-control state machine modeled on the GCC rate controller.
+///
+/// While a classifier only answers *why* packets were lost, [`RateController`] turns a
+/// stream of [`UsageSignal`]s into an [`Action`] recommendation, so an application driving
+/// an encoder or sender can react directly instead of inventing its own policy.
+///
+/// The controller keeps a state in `{Increase, Hold, Decrease}`:
+///
+/// - an [`UsageSignal::Overuse`] signal always transitions to `Decrease`.
+/// - an [`UsageSignal::Normal`] signal moves `Hold` to `Increase` and `Decrease` to `Hold`.
+/// - an [`UsageSignal::Underuse`] signal moves to `Hold`.
+///
+/// In `Increase` the target rate is bumped multiplicatively (×1.08 per elapsed second) when
+/// far from the last stable rate, and additively when close to it. In `Decrease` the target
+/// rate is reset to `0.85 × measured_receive_rate`.
+///
+/// ```rust
+/// use packet_loss_classification::{RateController, Spike, Action};
+///
+/// let mut spike = Spike::default();
+/// let mut controller = RateController::new(1_000.0);
+///
+/// let loss = spike.classify(20.0);
+/// let action = controller.update(loss.into(), 1.0, 900.0);
+/// assert_eq!((loss, action), (packet_loss_classification::PacketLoss::Wireless, Action::Increase));
+/// ````
+///
+/// [`Action`]: enum.Action.html
+/// [`RateController`]: struct.RateController.html
+/// [`UsageSignal`]: enum.UsageSignal.html
+/// [`UsageSignal::Normal`]: enum.UsageSignal.html
+/// [`UsageSignal::Overuse`]: enum.UsageSignal.html
+/// [`UsageSignal::Underuse`]: enum.UsageSignal.html
+#[derive(Debug)]
+pub struct RateController {
+    state: Action,
+    target_rate: f64,
+    last_stable_rate: f64,
+}
+
+impl RateController {
+    /// Creates a new rate controller starting out in `Hold` at `initial_rate`.
+    pub fn new(initial_rate: f64) -> Self {
+        Self {
+            state: Action::Hold,
+            target_rate: initial_rate,
+            last_stable_rate: initial_rate,
+        }
+    }
+
+    /// The currently suggested target sending rate.
+    pub fn target_rate(&self) -> f64 {
+        self.target_rate
+    }
+
+    /// Feeds a usage signal into the state machine and returns the resulting action.
+    ///
+    /// # Arguments
+    ///
+    /// - `signal`: the current usage signal, usually obtained from a classifier's
+    /// [`PacketLoss`] verdict via `.into()`.
+    /// - `elapsed`: time in seconds since the last call to `update`.
+    /// - `measured_receive_rate`: the rate currently observed to actually arrive at the
+    /// receiver, used to compute the new target rate on `Decrease`.
+    ///
+    /// [`PacketLoss`]: enum.PacketLoss.html
+    pub fn update(&mut self, signal: UsageSignal, elapsed: f64, measured_receive_rate: f64) -> Action {
+        assert!(elapsed >= 0.0);
+        assert!(measured_receive_rate >= 0.0);
+
+        self.state = match (self.state, signal) {
+            (_, UsageSignal::Overuse) => Action::Decrease,
+            (Action::Hold, UsageSignal::Normal) => Action::Increase,
+            (Action::Decrease, UsageSignal::Normal) => Action::Hold,
+            (state, UsageSignal::Normal) => state,
+            (_, UsageSignal::Underuse) => Action::Hold,
+        };
+
+        match self.state {
+            Action::Increase => {
+                let near_stable_rate = self.target_rate >= 0.95 * self.last_stable_rate
+                    && self.target_rate <= 1.05 * self.last_stable_rate;
+                if near_stable_rate {
+                    self.target_rate += 1_000.0 * elapsed;
+                } else {
+                    self.target_rate *= 1.08f64.powf(elapsed);
+                }
+            }
+            Action::Decrease => {
+                self.target_rate = 0.85 * measured_receive_rate;
+                self.last_stable_rate = self.target_rate;
+            }
+            Action::Hold => {}
+        }
+
+        self.state
+    }
+}
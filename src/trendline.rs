@@ -0,0 +1,192 @@
+use std::collections::VecDeque;
+
+use crate::{PacketLoss, UsageSignal};
+
+const WINDOW_SIZE: usize = 20;
+const GAMMA_MIN: f64 = 6.0;
+const GAMMA_MAX: f64 = 600.0;
+const K_UP: f64 = 0.01;
+const K_DOWN: f64 = 0.00018;
+
+/// Packet loss classifier based on the Google Congestion Control (GCC) trendline
+/// delay-gradient estimator.
+///
+/// Unlike [`Trend`], which reduces the delay trend to a single increment/decrement
+/// counter, [`Trendline`] fits a least-squares regression line over a sliding window
+/// of smoothed inter-packet delay variations and compares its slope against an
+/// adaptive threshold. This makes it far more stable than [`Trend`] when the delay
+/// sits close to the threshold.
+///
+/// For every packet the inter-packet delay variation is computed as
+///
+/// `d(i) = (arrival_i - arrival_{i-1}) - (send_i - send_{i-1})`
+///
+/// which is accumulated and smoothed with an exponential moving average. A linear
+/// regression over the last ~20 `(arrival_time, smoothed)` pairs yields a slope that
+/// is scaled into an estimate `m`. `m` is compared against an adaptive threshold
+/// `gamma` that grows quickly while overused and decays slowly otherwise.
+///
+/// ```rust
+/// use packet_loss_classification::{Trendline, PacketLoss};
+///
+/// let mut trendline = Trendline::default();
+/// let mut send = 0.0;
+/// let mut arrival = 0.0;
+/// for i in 0..17 {
+///     send += 20.0;
+///     arrival += 20.0 + i as f64 * 2.0;
+///     assert_eq!(trendline.classify(send, arrival), PacketLoss::Wireless);
+/// }
+/// send += 20.0;
+/// arrival += 20.0 + 17.0 * 2.0;
+/// assert_eq!(trendline.classify(send, arrival), PacketLoss::Congestion);
+/// ````
+///
+/// [`Trend`]: struct.Trend.html
+/// [`Trendline`]: struct.Trendline.html
+/// [`ZBS`]: struct.ZBS.html
+#[derive(Debug)]
+pub struct Trendline {
+    previous: Option<(f64, f64)>,
+    acc: f64,
+    smoothed: f64,
+    window: VecDeque<(f64, f64)>,
+    gamma: f64,
+    t_prev: Option<f64>,
+    overuse_duration: f64,
+    overuse_time_threshold: f64,
+}
+
+impl Default for Trendline {
+    fn default() -> Self {
+        Self {
+            previous: None,
+            acc: 0.0,
+            smoothed: 0.0,
+            window: VecDeque::with_capacity(WINDOW_SIZE),
+            gamma: 12.5,
+            t_prev: None,
+            overuse_duration: 0.0,
+            overuse_time_threshold: 100.0,
+        }
+    }
+}
+
+impl Trendline {
+    /// Creates a new packet loss classifier based on the GCC trendline scheme.
+    ///
+    /// # Arguments
+    ///
+    /// - `overuse_time_threshold`: minimum duration the overuse signal has to persist
+    /// before it is escalated to [`PacketLoss::Congestion`].
+    ///
+    /// [`PacketLoss::Congestion`]: enum.PacketLoss.html
+    pub fn new(overuse_time_threshold: f64) -> Self {
+        Self {
+            overuse_time_threshold,
+            ..Self::default()
+        }
+    }
+
+    /// Classifies the reason of packet loss based on the send and arrival timestamps
+    /// of the current packet.
+    ///
+    /// This function is called with the send and arrival timestamp of the current
+    /// packet if previous packets were lost.
+    ///
+    /// # Arguments
+    ///
+    /// - `send_time`: timestamp at which the current packet was sent.
+    /// - `arrival_time`: timestamp at which the current packet arrived.
+    pub fn classify(&mut self, send_time: f64, arrival_time: f64) -> PacketLoss {
+        match self.trend(send_time, arrival_time) {
+            Some(m) if m > self.gamma && self.overuse_duration > self.overuse_time_threshold => {
+                PacketLoss::Congestion
+            }
+            _ => PacketLoss::Wireless,
+        }
+    }
+
+    /// Classifies the current packet like `classify`, but also surfaces the underuse case
+    /// that [`PacketLoss`] has no variant for.
+    ///
+    /// This is the signal [`RateController`] expects in order to move back to `Hold` once a
+    /// deliberate rate decrease has drained the queue.
+    ///
+    /// [`PacketLoss`]: enum.PacketLoss.html
+    /// [`RateController`]: struct.RateController.html
+    pub fn signal(&mut self, send_time: f64, arrival_time: f64) -> UsageSignal {
+        match self.trend(send_time, arrival_time) {
+            Some(m) if m > self.gamma && self.overuse_duration > self.overuse_time_threshold => {
+                UsageSignal::Overuse
+            }
+            Some(m) if m < -self.gamma => UsageSignal::Underuse,
+            _ => UsageSignal::Normal,
+        }
+    }
+
+    /// Updates the internal delay-gradient estimate and adaptive threshold, returning
+    /// the current estimate `m` once enough samples have been observed.
+    fn trend(&mut self, send_time: f64, arrival_time: f64) -> Option<f64> {
+        assert!(arrival_time >= send_time);
+        if let Some((_, prev_arrival)) = self.previous {
+            assert!(arrival_time >= prev_arrival);
+        }
+
+        let (prev_send, prev_arrival) = match self.previous.replace((send_time, arrival_time)) {
+            Some(previous) => previous,
+            None => {
+                self.t_prev = Some(arrival_time);
+                return None;
+            }
+        };
+
+        let d = (arrival_time - prev_arrival) - (send_time - prev_send);
+        self.acc += d;
+        self.smoothed = 0.9 * self.smoothed + 0.1 * self.acc;
+
+        self.window.push_back((arrival_time, self.smoothed));
+        if self.window.len() > WINDOW_SIZE {
+            self.window.pop_front();
+        }
+
+        let m = self.slope() * self.window.len().min(60) as f64 * 4.0;
+
+        let t_prev = self.t_prev.unwrap_or(arrival_time);
+        let dt = arrival_time - t_prev;
+        self.t_prev = Some(arrival_time);
+
+        let k = if m.abs() > self.gamma { K_UP } else { K_DOWN };
+        self.gamma = (self.gamma + dt * k * (m.abs() - self.gamma)).clamp(GAMMA_MIN, GAMMA_MAX);
+
+        if m > self.gamma {
+            self.overuse_duration += dt;
+        } else {
+            self.overuse_duration = 0.0;
+        }
+
+        Some(m)
+    }
+
+    /// Fits a least-squares regression line over the current window and returns its slope.
+    fn slope(&self) -> f64 {
+        let n = self.window.len() as f64;
+        if n < 2.0 {
+            return 0.0;
+        }
+
+        let (sum_x, sum_y, sum_xy, sum_xx) = self
+            .window
+            .iter()
+            .fold((0.0, 0.0, 0.0, 0.0), |(sx, sy, sxy, sxx), &(x, y)| {
+                (sx + x, sy + y, sxy + x * y, sxx + x * x)
+            });
+
+        let denominator = n * sum_xx - sum_x * sum_x;
+        if denominator.abs() < f64::EPSILON {
+            0.0
+        } else {
+            (n * sum_xy - sum_x * sum_y) / denominator
+        }
+    }
+}
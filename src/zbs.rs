@@ -1,4 +1,12 @@
-use crate::{MBiaz, PacketLoss, Spike, ZigZag};
+use crate::{BandwidthEstimator, MBiaz, PacketLoss, Spike, Trendline, ZigZag};
+
+/// Default number of consecutive `Congestion` verdicts after which [`ZBS::default`] escalates
+/// a sustained congestion event to [`PacketLoss::PersistentCongestion`], mirroring QUIC's
+/// persistent congestion threshold.
+///
+/// [`PacketLoss::PersistentCongestion`]: enum.PacketLoss.html
+/// [`ZBS::default`]: struct.ZBS.html
+const DEFAULT_PERSISTENT_CONGESTION_THRESHOLD: u32 = 3;
 
 /// Packet loss classifier based on the [`ZBS`] hybrid scheme.
 ///
@@ -9,14 +17,29 @@ use crate::{MBiaz, PacketLoss, Spike, ZigZag};
 /// In WLH (Wireless Last Hop) topologies [`ZigZag`] and [`MBiaz`] perform well, while in WB
 /// (Wireless Backbone) [`Spike`] performs best and [`ZigZag`] works reasonably well.
 ///
+/// [`Trendline`]:
+///   - ambiguous network topology estimation (e.g. at startup), as a more stable
+///     replacement for a raw ascending/descending delay check
 /// [`ZigZag`]:
-///   - ambiguous network topology estimation (e.g. at startup)
+///   - borderline WLH/WB topology
 /// [`Spike`]:
 ///   - slowest link underutilized (ROTT is close to its minimum)
 ///   - multiple competing flows
 /// [`MBiaz`]
 ///   - wireless link is bottleneck and not shared
 ///
+/// Across calls, [`ZBS`] also tracks the number of consecutive `Congestion` verdicts and
+/// escalates to [`PacketLoss::PersistentCongestion`] once that streak reaches the
+/// `persistent_congestion_threshold` passed to [`ZBS::new`], distinguishing a transient queue
+/// spike from a collapsed path.
+///
+/// Callers may additionally feed delivery-rate feedback through [`record_delivery`], which
+/// sharpens the choice made when ROTT sits near its minimum: a delivery rate pinned at the
+/// windowed-max bandwidth estimate favors [`Spike`] (underutilized shared backbone), while a
+/// saturated single-hop bottleneck favors [`MBiaz`].
+///
+/// [`record_delivery`]: ZBS::record_delivery
+///
 /// ```rust
 /// use packet_loss_classification::{ZBS, PacketLoss};
 ///
@@ -27,6 +50,7 @@ use crate::{MBiaz, PacketLoss, Spike, ZigZag};
 ///
 /// [`MBiaz`]: struct.MBiaz.html
 /// [`Spike`]: struct.Spike.html
+/// [`Trendline`]: struct.Trendline.html
 /// [`ZBS`]: struct.ZBS.html
 /// [`ZigZag`]: struct.ZigZag.html
 #[derive(Debug)]
@@ -34,9 +58,14 @@ pub struct ZBS {
     mbiaz: MBiaz,
     spike: Spike,
     zigzag: ZigZag,
+    trendline: Trendline,
     t_avg: f64,
     t_min: f64,
     rott_min: f64,
+    cumulative_arrival: f64,
+    congestion_streak: u32,
+    persistent_congestion_threshold: u32,
+    bandwidth: BandwidthEstimator,
 }
 
 impl Default for ZBS {
@@ -45,9 +74,14 @@ impl Default for ZBS {
             mbiaz: MBiaz::default(),
             spike: Spike::default(),
             zigzag: ZigZag::default(),
+            trendline: Trendline::default(),
             t_avg: 0.0,
             t_min: std::f64::MAX,
             rott_min: std::f64::MAX,
+            cumulative_arrival: 0.0,
+            congestion_streak: 0,
+            persistent_congestion_threshold: DEFAULT_PERSISTENT_CONGESTION_THRESHOLD,
+            bandwidth: BandwidthEstimator::default(),
         }
     }
 }
@@ -60,23 +94,58 @@ impl ZBS {
     /// - `mbiaz`: classifier based on the [`MBiaz`] scheme.
     /// - `spike`: classifier based on the [`Spike`] scheme.
     /// - `zigzag`: classifier based on the [`ZigZag`] scheme.
+    /// - `trendline`: classifier based on the [`Trendline`] scheme.
+    /// - `persistent_congestion_threshold`: number of consecutive `Congestion` verdicts after
+    /// which a sustained congestion event is escalated to
+    /// [`PacketLoss::PersistentCongestion`].
     ///
     /// [`MBiaz`]: struct.MBiaz.html
+    /// [`PacketLoss::PersistentCongestion`]: enum.PacketLoss.html
     /// [`Spike`]: struct.Spike.html
     /// [`Trend`]: struct.Trend.html
+    /// [`Trendline`]: struct.Trendline.html
     /// [`ZBS`]: struct.ZBS.html
     /// [`ZigZag`]: struct.ZigZag.html
-    pub fn new(mbiaz: MBiaz, spike: Spike, zigzag: ZigZag) -> Self {
+    pub fn new(
+        mbiaz: MBiaz,
+        spike: Spike,
+        zigzag: ZigZag,
+        trendline: Trendline,
+        persistent_congestion_threshold: u32,
+    ) -> Self {
         Self {
             mbiaz,
             spike,
             zigzag,
+            trendline,
             t_avg: 0.0,
             t_min: std::f64::MAX,
             rott_min: std::f64::MAX,
+            cumulative_arrival: 0.0,
+            congestion_streak: 0,
+            persistent_congestion_threshold,
+            bandwidth: BandwidthEstimator::default(),
         }
     }
 
+    /// Records a delivery-rate feedback sample used to sharpen the topology estimation in
+    /// `classify`. Calling this is optional; without it [`ZBS`] falls back to topology
+    /// estimation based solely on `t_narr`.
+    ///
+    /// # Arguments
+    ///
+    /// - `delivered_bytes`: number of bytes delivered since the last feedback.
+    /// - `interval`: time elapsed since the last feedback.
+    /// - `rott`: relative one-way trip time observed for this feedback.
+    pub fn record_delivery(&mut self, delivered_bytes: f64, interval: f64, rott: f64) {
+        self.bandwidth.update(delivered_bytes, interval, rott);
+    }
+
+    /// The windowed-max estimate of the bottleneck bandwidth from recorded delivery feedback.
+    pub fn bottleneck_bandwidth(&self) -> f64 {
+        self.bandwidth.bottleneck_bandwidth()
+    }
+
     /// Classifies the reason of packet loss based on the ROTT of the current packet.
     ///
     /// # Arguments
@@ -104,15 +173,24 @@ impl ZBS {
         self.t_avg = 0.875 * self.t_avg
             + 0.125 * interarrival_time * interarrival_time / num_lost_packets as f64;
 
+        self.cumulative_arrival += interarrival_time;
+
         // In WLH topology t_narr ~ 1, while in WB topology t_narr ~ N, where N is the number of flows
         // sharing the link with the lowest bandwidth.
         let t_narr = self.t_avg / self.t_min;
 
-        if rott < self.rott_min + 0.05 * self.t_min {
-            self.spike.classify(rott)
+        let verdict = if rott < self.rott_min + 0.05 * self.t_min {
+            if self.bandwidth.has_samples() && !self.bandwidth.is_delivery_rate_pinned_at_max() {
+                self.mbiaz.classify(interarrival_time, num_lost_packets)
+            } else {
+                self.spike.classify(rott)
+            }
         } else {
             if t_narr < 0.875 {
-                self.zigzag.classify(rott, num_lost_packets)
+                // Ambiguous topology: Trendline's delay-gradient estimate is far more
+                // stable here than a raw ascending/descending ROTT check.
+                let send_time = self.cumulative_arrival - rott;
+                self.trendline.classify(send_time, self.cumulative_arrival)
             } else if t_narr < 1.5 {
                 self.mbiaz.classify(interarrival_time, num_lost_packets)
             } else if t_narr < 2.0 {
@@ -120,6 +198,27 @@ impl ZBS {
             } else {
                 self.spike.classify(rott)
             }
+        };
+
+        self.escalate(verdict)
+    }
+
+    /// Tracks the number of consecutive `Congestion` verdicts and escalates to
+    /// [`PacketLoss::PersistentCongestion`] once it reaches `persistent_congestion_threshold`.
+    ///
+    /// [`PacketLoss::PersistentCongestion`]: enum.PacketLoss.html
+    fn escalate(&mut self, verdict: PacketLoss) -> PacketLoss {
+        if verdict != PacketLoss::Congestion {
+            self.congestion_streak = 0;
+            return verdict;
+        }
+
+        self.congestion_streak += 1;
+
+        if self.congestion_streak >= self.persistent_congestion_threshold {
+            PacketLoss::PersistentCongestion
+        } else {
+            verdict
         }
     }
 }